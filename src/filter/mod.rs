@@ -7,8 +7,10 @@ mod or;
 mod or_else;
 mod recover;
 mod service;
+mod then;
 mod unify;
 mod unit;
+mod untuple_one;
 mod wrap;
 
 use futures::{future, Future, IntoFuture};
@@ -25,8 +27,10 @@ pub(crate) use self::map_err::MapErr;
 pub(crate) use self::or::Or;
 use self::or_else::OrElse;
 use self::recover::Recover;
+use self::then::Then;
 use self::unify::Unify;
 use self::unit::Unit;
+use self::untuple_one::UntupleOne;
 pub(crate) use self::wrap::{WrapSealed, Wrap};
 
 // A crate-private base trait, allowing the actual `filter` method to change
@@ -241,6 +245,37 @@ pub trait Filter: FilterBase {
         }
     }
 
+    /// Composes this `Filter` with a function receiving the extracted value.
+    ///
+    /// The function should return some `IntoFuture` type, but unlike
+    /// `and_then`, the future's `Error` type must be `Self::Error`, and
+    /// doesn't require a `CombineRejection` to merge the two. This is
+    /// useful when the function is infallible, but still needs to do
+    /// some asynchronous work, like a database lookup or template render.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use warp::Filter;
+    ///
+    /// // Render a greeting after `/:id`
+    /// warp::path::param().then(|id: u64| {
+    ///     Ok(format!("Hello #{}", id))
+    /// });
+    /// ```
+    fn then<F>(self, fun: F) -> Then<Self, F>
+    where
+        Self: Sized,
+        F: Func<Self::Extract> + Clone,
+        F::Output: IntoFuture<Error=Self::Error> + Send,
+        <F::Output as IntoFuture>::Future: Send,
+    {
+        Then {
+            filter: self,
+            callback: fun,
+        }
+    }
+
     /// Compose this `Filter` with a function receiving an error.
     ///
     /// The function should return some `IntoFuture` type yielding the
@@ -310,6 +345,37 @@ pub trait Filter: FilterBase {
         }
     }
 
+    /// Removes one layer of tupling from this `Filter`'s extract: a filter
+    /// extracting `(T,)`, where `T` is itself a tuple, becomes a filter
+    /// extracting `T`, so its elements are passed as separate arguments.
+    ///
+    /// This is useful for when a `map` or `and_then` callback bundles
+    /// several values together into a single tuple extract (for example,
+    /// to hand them off as one unit), and a later combinator needs to
+    /// see those values again as separate arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use warp::Filter;
+    ///
+    /// let plus_one = warp::any()
+    ///     .map(|| (41, "a"))
+    ///     .untuple_one()
+    ///     .map(|n: i32, s: &'static str| {
+    ///         format!("{}: {}", n + 1, s)
+    ///     });
+    /// ```
+    fn untuple_one<T>(self) -> UntupleOne<Self>
+    where
+        Self: Filter<Extract=(T,)> + Sized,
+        T: Tuple,
+    {
+        UntupleOne {
+            filter: self,
+        }
+    }
+
     /// Wraps the current filter with some wrapper.
     ///
     /// The wrapper may do some preparation work before starting this filter,
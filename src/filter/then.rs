@@ -0,0 +1,71 @@
+use futures::{Async, Future, IntoFuture, Poll};
+
+use super::{Filter, FilterBase, Func};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Then<T, F> {
+    pub(super) filter: T,
+    pub(super) callback: F,
+}
+
+impl<T, F> FilterBase for Then<T, F>
+where
+    T: Filter,
+    F: Func<T::Extract> + Clone,
+    F::Output: IntoFuture<Error=T::Error> + Send,
+    <F::Output as IntoFuture>::Future: Send,
+{
+    type Extract = (<F::Output as IntoFuture>::Item,);
+    type Error = T::Error;
+    type Future = ThenFuture<T, F>;
+
+    #[inline]
+    fn filter(&self) -> Self::Future {
+        ThenFuture {
+            state: State::First(self.filter.filter(), self.callback.clone()),
+        }
+    }
+}
+
+#[allow(missing_debug_implementations)]
+pub struct ThenFuture<T: Filter, F> where F: Func<T::Extract> {
+    state: State<T, F>,
+}
+
+enum State<T: Filter, F> where F: Func<T::Extract> {
+    First(T::Future, F),
+    Second(<F::Output as IntoFuture>::Future),
+    Done,
+}
+
+impl<T, F> Future for ThenFuture<T, F>
+where
+    T: Filter,
+    F: Func<T::Extract>,
+    F::Output: IntoFuture<Error=T::Error>,
+{
+    type Item = (<F::Output as IntoFuture>::Item,);
+    type Error = T::Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            let second = match self.state {
+                State::First(ref mut first, ref callback) => {
+                    match first.poll() {
+                        Ok(Async::Ready(ex)) => callback.call(ex),
+                        Ok(Async::NotReady) => return Ok(Async::NotReady),
+                        Err(e) => return Err(e),
+                    }
+                },
+                State::Second(ref mut second) => {
+                    let item = try_ready!(second.poll());
+                    self.state = State::Done;
+                    return Ok(Async::Ready((item,)));
+                },
+                State::Done => panic!("polled after complete"),
+            };
+
+            self.state = State::Second(second.into_future());
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use futures::{Async, Future, Poll};
+
+use super::{Filter, FilterBase, Tuple};
+
+#[derive(Clone, Copy, Debug)]
+pub struct UntupleOne<T> {
+    pub(super) filter: T,
+}
+
+impl<T, F> FilterBase for UntupleOne<T>
+where
+    T: Filter<Extract=(F,)>,
+    F: Tuple,
+{
+    type Extract = F;
+    type Error = T::Error;
+    type Future = UntupleOneFuture<T>;
+
+    #[inline]
+    fn filter(&self) -> Self::Future {
+        UntupleOneFuture {
+            extract: self.filter.filter(),
+        }
+    }
+}
+
+#[allow(missing_debug_implementations)]
+pub struct UntupleOneFuture<T: Filter> {
+    extract: T::Future,
+}
+
+impl<T, F> Future for UntupleOneFuture<T>
+where
+    T: Filter<Extract=(F,)>,
+    F: Tuple,
+{
+    type Item = F;
+    type Error = T::Error;
+
+    #[inline]
+    fn poll(&mut self) -> Poll<F, T::Error> {
+        let (item,) = try_ready!(self.extract.poll());
+        Ok(Async::Ready(item))
+    }
+}